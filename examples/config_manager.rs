@@ -1,27 +1,68 @@
-use repository::config::{ConfigContract, Repository};
+use repository::config::{ConfigContract, Repository, Value};
+use repository::env::EnvSource;
+use repository::file::FileSource;
+use serde::Deserialize;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-fn main() -> std::io::Result<()> {
-    let mut items = HashMap::new();
-    let file = File::open("config.txt")?;
-    let reader = BufReader::new(file);
-
-    for line in reader.lines() {
-        let line = line?;
-        let parts: Vec<&str> = line.split("=").collect();
-        items.insert(parts[0].to_owned(), parts[1].to_owned());
+use std::error::Error;
+
+#[derive(Deserialize)]
+struct AppSettings {
+    foo: String,
+    database: DatabaseSettings,
+}
+
+#[derive(Deserialize)]
+struct DatabaseSettings {
+    host: String,
+    port: i64,
+}
+
+fn print_value(key: &str, value: &Value) {
+    match value {
+        Value::Table(table) => {
+            for (segment, child) in table {
+                print_value(&format!("{}.{}", key, segment), child);
+            }
+        }
+        Value::Nil => println!("{}=", key),
+        Value::Boolean(b) => println!("{}={}", key, b),
+        Value::Integer(i) => println!("{}={}", key, i),
+        Value::Float(f) => println!("{}={}", key, f),
+        Value::String(s) => println!("{}={}", key, s),
+        Value::Array(items) => println!("{}={:?}", key, items),
     }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut config = Repository::new(HashMap::new());
+    config.set_default("foo", Value::String("bar".to_string()))?;
+    config.set_default("database.host", Value::String("localhost".to_string()))?;
+    config.set_default("database.port", Value::Integer(5432))?;
+
+    // `examples/config.toml` overrides the defaults above; it's optional so
+    // the example still runs if the file is missing.
+    config.add_source(Box::new(
+        FileSource::new("examples/config.toml")?.optional(),
+    ))?;
 
-    let mut config = Repository::new(items);
-    config.set("foo", "baz");
-    config.set("bar", "qux");
+    // Environment variables take precedence over the file, e.g.
+    // `APP_DATABASE__PORT=5433` would override `database.port`.
+    config.add_source(Box::new(
+        EnvSource::new().with_prefix("APP_").with_separator("__"),
+    ))?;
+
+    let config = config.freeze();
 
     println!("All config items:");
     for (key, value) in config.all() {
-        println!("{}={}", key, value);
+        print_value(key, value);
     }
 
+    let settings: AppSettings = config.deserialize()?;
+    println!(
+        "\nDeserialized settings: foo={}, database.host={}, database.port={}",
+        settings.foo, settings.database.host, settings.database.port
+    );
+
     Ok(())
 }
@@ -1,4 +1,86 @@
 use std::collections::HashMap;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+
+use crate::de::ValueDeserializer;
+use crate::error::Error;
+use crate::source::Source;
+
+/// A typed configuration value.
+///
+/// A key such as `"database.host"` is split on `.` into segments that walk
+/// through nested `Table` values until the final segment, which holds a
+/// scalar. This is what lets [`Repository`] model hierarchical, typed config
+/// on top of a plain `HashMap`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// The absence of a value.
+    Nil,
+    /// A boolean value.
+    Boolean(bool),
+    /// A signed integer value.
+    Integer(i64),
+    /// A floating point value.
+    Float(f64),
+    /// A string value.
+    String(String),
+    /// An ordered list of values.
+    Array(Vec<Value>),
+    /// An intermediate table reached while walking a dotted key path.
+    Table(HashMap<String, Value>),
+}
+
+/// Splits `key` on `.` and walks/creates intermediate `Table` values in
+/// `map`, inserting `value` at the final segment.
+///
+/// Shared by [`Repository`]'s own dotted-key writes and by [`Source`]
+/// implementations (such as `EnvSource`) that need to turn a flat,
+/// separator-joined name into the same nested shape.
+pub(crate) fn set_path(map: &mut HashMap<String, Value>, key: &str, value: Value) {
+    let segments: Vec<&str> = key.split('.').collect();
+    let (last, init) = segments.split_last().expect("key must not be empty");
+
+    let mut current = map;
+    for segment in init {
+        let entry = current
+            .entry((*segment).to_string())
+            .or_insert_with(|| Value::Table(HashMap::new()));
+        if !matches!(entry, Value::Table(_)) {
+            *entry = Value::Table(HashMap::new());
+        }
+        match entry {
+            Value::Table(table) => current = table,
+            _ => unreachable!("just normalized to a table"),
+        }
+    }
+    current.insert((*last).to_string(), value);
+}
+
+fn merge_maps(base: &mut HashMap<String, Value>, incoming: &HashMap<String, Value>) {
+    for (key, incoming_value) in incoming {
+        match (base.get_mut(key), incoming_value) {
+            (Some(Value::Table(base_table)), Value::Table(incoming_table)) => {
+                merge_maps(base_table, incoming_table);
+            }
+            _ => {
+                base.insert(key.clone(), incoming_value.clone());
+            }
+        }
+    }
+}
+
+/// Returned by a [`Repository`] mutation attempted after [`Repository::freeze`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrozenError;
+
+impl fmt::Display for FrozenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot mutate a frozen Repository")
+    }
+}
+
+impl std::error::Error for FrozenError {}
 
 /// A contract for configuration management.
 pub trait ConfigContract {
@@ -17,7 +99,11 @@ pub trait ConfigContract {
     /// ```
     fn has(&self, key: &str) -> bool;
 
-    /// Gets the value associated with the given key in the configuration.
+    /// Gets the value associated with the given key, stringifying scalars.
+    ///
+    /// A `.`-delimited key is treated as a path into nested tables; it
+    /// resolves to `None` if any segment is missing, or if the path lands on
+    /// an array or table rather than a scalar.
     ///
     /// # Examples
     ///
@@ -27,12 +113,69 @@ pub trait ConfigContract {
     ///
     /// let config_items = [("foo".to_string(), "bar".to_string())].iter().cloned().collect::<HashMap<_,_>>();
     /// let config = Repository::new(config_items);
-    /// assert_eq!(config.get("foo"), Some("bar"));
+    /// assert_eq!(config.get("foo").as_deref(), Some("bar"));
     /// assert_eq!(config.get("missing"), None);
     /// ```
-    fn get(&self, key: &str) -> Option<&str>;
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Gets the value at `key` coerced to a `bool`.
+    ///
+    /// Strings are parsed with `"true"`/`"false"`, and integers coerce
+    /// through nonzero-is-true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use repository::config::{ConfigContract, Repository};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut config = Repository::new(HashMap::new());
+    /// config.set("debug", "true").unwrap();
+    /// assert_eq!(config.get_bool("debug"), Some(true));
+    /// ```
+    fn get_bool(&self, key: &str) -> Option<bool>;
 
-    /// Sets the value associated with the given key in the configuration.
+    /// Gets the value at `key` coerced to an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use repository::config::{ConfigContract, Repository};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut config = Repository::new(HashMap::new());
+    /// config.set("port", "8080").unwrap();
+    /// assert_eq!(config.get_int("port"), Some(8080));
+    /// ```
+    fn get_int(&self, key: &str) -> Option<i64>;
+
+    /// Gets the value at `key` as a borrowed string, without stringifying
+    /// other scalar types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use repository::config::{ConfigContract, Repository};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut config = Repository::new(HashMap::new());
+    /// config.set("name", "repository").unwrap();
+    /// assert_eq!(config.get_str("name"), Some("repository"));
+    /// ```
+    fn get_str(&self, key: &str) -> Option<&str>;
+
+    /// Gets the value at `key` as an array, if that is what's stored there.
+    fn get_array(&self, key: &str) -> Option<&Vec<Value>>;
+
+    /// Gets the value at `key` as a table, if that is what's stored there.
+    fn get_table(&self, key: &str) -> Option<&HashMap<String, Value>>;
+
+    /// Sets the value associated with the given key as an override, taking
+    /// precedence over defaults and sources.
+    ///
+    /// A `.`-delimited key walks or creates intermediate tables for each
+    /// segment and inserts the leaf value at the end. Fails with
+    /// [`FrozenError`] once the repository has been [`frozen`](Repository::freeze).
     ///
     /// # Examples
     ///
@@ -42,33 +185,68 @@ pub trait ConfigContract {
     ///
     /// let mut config_items = [("foo".to_string(), "bar".to_string())].iter().cloned().collect::<HashMap<_,_>>();
     /// let mut config = Repository::new(config_items);
-    /// config.set("foo", "baz");
-    /// assert_eq!(config.get("foo"), Some("baz"));
+    /// config.set("foo", "baz").unwrap();
+    /// config.set("database.host", "localhost").unwrap();
+    /// assert_eq!(config.get("foo").as_deref(), Some("baz"));
+    /// assert_eq!(config.get("database.host").as_deref(), Some("localhost"));
     /// ```
-    fn set(&mut self, key: &str, value: &str);
+    fn set(&mut self, key: &str, value: &str) -> Result<(), FrozenError>;
 
-    /// Gets all configuration items as a reference to a hashmap.
+    /// Gets a merged snapshot of defaults, sources, and overrides as a
+    /// reference to the underlying value map.
     ///
     /// # Examples
     ///
     /// ```
-    /// use repository::config::{ConfigContract, Repository};
+    /// use repository::config::{ConfigContract, Repository, Value};
     /// use std::collections::HashMap;
     ///
     /// let config_items = [("foo".to_string(), "bar".to_string())].iter().cloned().collect::<HashMap<_,_>>();
     /// let config = Repository::new(config_items);
-    /// assert_eq!(config.all().get("foo"), Some(&"bar".to_string()));
+    /// assert_eq!(config.all().get("foo"), Some(&Value::String("bar".to_string())));
     /// ```
-    fn all(&self) -> &HashMap<String, String>;
+    fn all(&self) -> &HashMap<String, Value>;
+}
+
+/// The internal storage backing a [`Repository`].
+///
+/// `Mutable` keeps the three precedence layers separate, plus a `snapshot`
+/// cache rebuilt by [`Repository::refresh`] so repeated reads are cheap.
+/// `Frozen` drops the layers entirely in favor of a single resolved `cache`,
+/// computed once at [`Repository::freeze`] time, guaranteeing the config
+/// can't drift after application startup.
+enum State {
+    Mutable {
+        defaults: HashMap<String, Value>,
+        sources: Vec<Box<dyn Source>>,
+        overrides: HashMap<String, Value>,
+        snapshot: HashMap<String, Value>,
+    },
+    Frozen {
+        cache: HashMap<String, Value>,
+    },
 }
 
-/// A configuration repository that implements the ConfigContract.
+/// A layered configuration repository.
+///
+/// A `Repository` composes three layers in ascending precedence:
+/// `defaults`, an ordered list of [`Source`]s, and `overrides` (written
+/// through [`ConfigContract::set`]). Resolving a key checks `overrides`
+/// first, then each source in reverse insertion order, then `defaults`,
+/// returning the first hit. In practice this is implemented by deep-merging
+/// the layers into a cached snapshot on [`Repository::refresh`], so repeated
+/// reads don't re-walk every source. Calling [`Repository::freeze`] collapses
+/// this into a single immutable cache.
 pub struct Repository {
-    items: HashMap<String, String>,
+    state: State,
 }
 
 impl Repository {
-    /// Constructs a new Repository with the given items.
+    /// Constructs a new Repository with the given items as overrides.
+    ///
+    /// Each key is treated as a `.`-delimited path, the same as
+    /// [`ConfigContract::set`]: `"database.host"` lands in a nested
+    /// `database` table rather than as a literal flat key.
     ///
     /// # Examples
     ///
@@ -78,13 +256,213 @@ impl Repository {
     ///
     /// let config_items = [("foo".to_string(), "bar".to_string())].iter().cloned().collect::<HashMap<_,_>>();
     /// let config = Repository::new(config_items);
-    /// assert_eq!(config.get("foo"), Some("bar"));
+    /// assert_eq!(config.get("foo").as_deref(), Some("bar"));
     /// ```
     pub fn new(items: HashMap<String, String>) -> Self {
-        Self { items }
+        let mut items: Vec<(String, String)> = items.into_iter().collect();
+        // Sorted so a prefix key (e.g. "database") is always set before any
+        // dotted child of it (e.g. "database.host") regardless of the
+        // HashMap's unspecified iteration order, making their conflict
+        // resolve deterministically: the child always wins, same as calling
+        // `set("database", ..)` followed by `set("database.host", ..)`.
+        items.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut overrides = HashMap::new();
+        for (key, value) in items {
+            set_path(&mut overrides, &key, Value::String(value));
+        }
+        let mut repository = Self {
+            state: State::Mutable {
+                defaults: HashMap::new(),
+                sources: Vec::new(),
+                overrides,
+                snapshot: HashMap::new(),
+            },
+        };
+        repository.refresh().expect("a freshly constructed Repository is never frozen");
+        repository
+    }
+
+    /// Sets a default value, used only when no source or override provides
+    /// the key. Fails with [`FrozenError`] once the repository is frozen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use repository::config::{ConfigContract, Repository, Value};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut config = Repository::new(HashMap::new());
+    /// config.set_default("port", Value::Integer(8080)).unwrap();
+    /// assert_eq!(config.get_int("port"), Some(8080));
+    ///
+    /// config.set("port", "9090").unwrap();
+    /// assert_eq!(config.get_int("port"), Some(9090));
+    /// ```
+    pub fn set_default(&mut self, key: &str, value: Value) -> Result<(), FrozenError> {
+        match &mut self.state {
+            State::Mutable { defaults, .. } => set_path(defaults, key, value),
+            State::Frozen { .. } => return Err(FrozenError),
+        }
+        self.refresh()
+    }
+
+    /// Adds a source to the end of the precedence chain, taking priority
+    /// over sources added before it and over defaults, but not overrides.
+    /// Fails with [`FrozenError`] once the repository is frozen.
+    pub fn add_source(&mut self, source: Box<dyn Source>) -> Result<(), FrozenError> {
+        match &mut self.state {
+            State::Mutable { sources, .. } => sources.push(source),
+            State::Frozen { .. } => return Err(FrozenError),
+        }
+        self.refresh()
+    }
+
+    /// Deep-merges `other`'s resolved configuration into this repository's
+    /// overrides. Where both sides hold a table at the same key, the tables
+    /// are merged recursively; otherwise the value from `other` overwrites
+    /// this repository's value. Fails with [`FrozenError`] once this
+    /// repository is frozen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use repository::config::{ConfigContract, Repository};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut base = Repository::new(HashMap::new());
+    /// base.set("a.b", "1").unwrap();
+    ///
+    /// let mut incoming = Repository::new(HashMap::new());
+    /// incoming.set("a.c", "2").unwrap();
+    ///
+    /// base.merge(&incoming).unwrap();
+    /// assert_eq!(base.get("a.b").as_deref(), Some("1"));
+    /// assert_eq!(base.get("a.c").as_deref(), Some("2"));
+    /// ```
+    pub fn merge(&mut self, other: &Repository) -> Result<(), FrozenError> {
+        match &mut self.state {
+            State::Mutable { overrides, .. } => merge_maps(overrides, other.all()),
+            State::Frozen { .. } => return Err(FrozenError),
+        }
+        self.refresh()
+    }
+
+    /// Re-reads every source and rebuilds the merged snapshot that `get`,
+    /// `has`, and `all` read from.
+    ///
+    /// Called automatically after any mutation, but also exposed so callers
+    /// can pick up changes a [`Source`] observes externally (an edited file,
+    /// a changed environment variable) without resetting the repository.
+    /// Fails with [`FrozenError`] once the repository is frozen, since a
+    /// frozen repository no longer holds the layers to re-merge.
+    pub fn refresh(&mut self) -> Result<(), FrozenError> {
+        match &mut self.state {
+            State::Mutable {
+                defaults,
+                sources,
+                overrides,
+                snapshot,
+            } => {
+                let mut merged = defaults.clone();
+                for source in sources.iter() {
+                    merge_maps(&mut merged, &source.collect());
+                }
+                merge_maps(&mut merged, overrides);
+                *snapshot = merged;
+                Ok(())
+            }
+            State::Frozen { .. } => Err(FrozenError),
+        }
+    }
+
+    /// Freezes the repository, computing the fully merged snapshot once and
+    /// discarding the defaults/sources/overrides layers.
+    ///
+    /// After this, `get`/`has`/`all` read the cached snapshot directly with
+    /// no merging work, and `set`/`set_default`/`add_source`/`merge` all
+    /// return [`FrozenError`] instead of mutating — guaranteeing config can't
+    /// drift after application startup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use repository::config::{ConfigContract, Repository};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut config_items = [("foo".to_string(), "bar".to_string())].iter().cloned().collect::<HashMap<_,_>>();
+    /// let mut config = Repository::new(config_items);
+    /// let frozen = config.freeze();
+    /// assert_eq!(frozen.get("foo").as_deref(), Some("bar"));
+    /// ```
+    pub fn freeze(self) -> Repository {
+        match self.state {
+            State::Mutable { snapshot, .. } => Repository {
+                state: State::Frozen { cache: snapshot },
+            },
+            frozen @ State::Frozen { .. } => Repository { state: frozen },
+        }
+    }
+
+    fn snapshot(&self) -> &HashMap<String, Value> {
+        match &self.state {
+            State::Mutable { snapshot, .. } => snapshot,
+            State::Frozen { cache } => cache,
+        }
+    }
+
+    /// Deserializes the merged snapshot into `T` in one call, instead of
+    /// fetching each field individually.
+    ///
+    /// `Value::Table` maps to a struct or map, `Value::Array` to a sequence,
+    /// and scalars to the matching Rust primitive; fields missing from the
+    /// config are left to `#[serde(default)]` (or fail, if there isn't one).
+    /// Errors report the offending dotted key path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use repository::config::Repository;
+    /// use serde::Deserialize;
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Settings {
+    ///     foo: String,
+    /// }
+    ///
+    /// let mut config_items = HashMap::new();
+    /// config_items.insert("foo".to_string(), "bar".to_string());
+    /// let config = Repository::new(config_items);
+    /// let settings: Settings = config.deserialize().unwrap();
+    /// assert_eq!(settings.foo, "bar");
+    /// ```
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        let root = Value::Table(self.snapshot().clone());
+        T::deserialize(ValueDeserializer::new(&root))
     }
-}
 
+    /// Walks a `.`-delimited key path over the merged snapshot. Each segment
+    /// indexes into a `Table` by key or an `Array` by parsed position, so
+    /// `"server.ports.0"` reaches the first element of a `ports` array
+    /// nested under `server`.
+    fn node_at(&self, key: &str) -> Option<&Value> {
+        let mut segments = key.split('.');
+        let mut current = self.snapshot().get(segments.next()?)?;
+        for segment in segments {
+            current = Self::descend(current, segment)?;
+        }
+        Some(current)
+    }
+
+    fn descend<'a>(value: &'a Value, segment: &str) -> Option<&'a Value> {
+        match value {
+            Value::Table(map) => map.get(segment),
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => None,
+        }
+    }
+}
 
 impl ConfigContract for Repository {
     /// Checks if a given key exists in the configuration.
@@ -101,10 +479,10 @@ impl ConfigContract for Repository {
     /// assert_eq!(config.has("missing"), false);
     /// ```
     fn has(&self, key: &str) -> bool {
-        self.items.contains_key(key)
+        self.node_at(key).is_some()
     }
 
-    /// Gets the value associated with the given key in the configuration.
+    /// Gets the value associated with the given key, stringifying scalars.
     ///
     /// # Examples
     ///
@@ -114,14 +492,61 @@ impl ConfigContract for Repository {
     ///
     /// let config_items = [("foo".to_string(), "bar".to_string())].iter().cloned().collect::<HashMap<_,_>>();
     /// let config = Repository::new(config_items);
-    /// assert_eq!(config.get("foo"), Some("bar"));
+    /// assert_eq!(config.get("foo").as_deref(), Some("bar"));
     /// assert_eq!(config.get("missing"), None);
     /// ```
-    fn get(&self, key: &str) -> Option<&str> {
-        self.items.get(key).map(|s| s.as_str())
+    fn get(&self, key: &str) -> Option<String> {
+        match self.node_at(key)? {
+            Value::Nil => None,
+            Value::Boolean(b) => Some(b.to_string()),
+            Value::Integer(i) => Some(i.to_string()),
+            Value::Float(f) => Some(f.to_string()),
+            Value::String(s) => Some(s.clone()),
+            Value::Array(_) | Value::Table(_) => None,
+        }
+    }
+
+    fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.node_at(key)? {
+            Value::Boolean(b) => Some(*b),
+            Value::Integer(i) => Some(*i != 0),
+            Value::String(s) => s.parse::<bool>().ok(),
+            _ => None,
+        }
     }
 
-    /// Sets the value associated with the given key in the configuration.
+    fn get_int(&self, key: &str) -> Option<i64> {
+        match self.node_at(key)? {
+            Value::Integer(i) => Some(*i),
+            Value::Float(f) => Some(*f as i64),
+            Value::String(s) => s.parse::<i64>().ok(),
+            _ => None,
+        }
+    }
+
+    fn get_str(&self, key: &str) -> Option<&str> {
+        match self.node_at(key)? {
+            Value::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    fn get_array(&self, key: &str) -> Option<&Vec<Value>> {
+        match self.node_at(key)? {
+            Value::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    fn get_table(&self, key: &str) -> Option<&HashMap<String, Value>> {
+        match self.node_at(key)? {
+            Value::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Sets the value associated with the given key as an override, taking
+    /// precedence over defaults and sources.
     ///
     /// # Examples
     ///
@@ -131,27 +556,34 @@ impl ConfigContract for Repository {
     ///
     /// let mut config_items = [("foo".to_string(), "bar".to_string())].iter().cloned().collect::<HashMap<_,_>>();
     /// let mut config = Repository::new(config_items);
-    /// config.set("foo", "baz");
-    /// assert_eq!(config.get("foo"), Some("baz"));
+    /// config.set("foo", "baz").unwrap();
+    /// assert_eq!(config.get("foo").as_deref(), Some("baz"));
     /// ```
-    fn set(&mut self, key: &str, value: &str) {
-        self.items.insert(key.to_string(), value.to_string());
+    fn set(&mut self, key: &str, value: &str) -> Result<(), FrozenError> {
+        match &mut self.state {
+            State::Mutable { overrides, .. } => {
+                set_path(overrides, key, Value::String(value.to_string()))
+            }
+            State::Frozen { .. } => return Err(FrozenError),
+        }
+        self.refresh()
     }
 
-    /// Gets all configuration items as a reference to a hashmap.
+    /// Gets a merged snapshot of defaults, sources, and overrides as a
+    /// reference to the underlying value map.
     ///
     /// # Examples
     ///
     /// ```
-    /// use repository::config::{ConfigContract, Repository};
+    /// use repository::config::{ConfigContract, Repository, Value};
     /// use std::collections::HashMap;
     ///
     /// let config_items = [("foo".to_string(), "bar".to_string())].iter().cloned().collect::<HashMap<_,_>>();
     /// let config = Repository::new(config_items);
-    /// assert_eq!(config.all().get("foo"), Some(&"bar".to_string()));
+    /// assert_eq!(config.all().get("foo"), Some(&Value::String("bar".to_string())));
     /// ```
-    fn all(&self) -> &HashMap<String, String> {
-        &self.items
+    fn all(&self) -> &HashMap<String, Value> {
+        self.snapshot()
     }
 }
 
@@ -172,16 +604,16 @@ mod tests {
         let mut items = HashMap::new();
         items.insert(String::from("key"), String::from("value"));
         let repo = Repository::new(items);
-        assert_eq!(Some("value"), repo.get("key"));
-        assert_eq!(None, repo.get("non_existent_key"));
+        assert_eq!(repo.get("key").as_deref(), Some("value"));
+        assert_eq!(repo.get("non_existent_key"), None);
     }
 
     #[test]
     fn test_set() {
         let items = HashMap::new();
         let mut repo = Repository::new(items);
-        repo.set("key", "value");
-        assert_eq!(Some("value"), repo.get("key"));
+        repo.set("key", "value").unwrap();
+        assert_eq!(repo.get("key").as_deref(), Some("value"));
     }
 
     #[test]
@@ -190,6 +622,265 @@ mod tests {
         items.insert(String::from("key1"), String::from("value1"));
         items.insert(String::from("key2"), String::from("value2"));
         let repo = Repository::new(items.clone());
-        assert_eq!(&items, repo.all());
+        assert_eq!(repo.all().get("key1"), Some(&Value::String("value1".to_string())));
+        assert_eq!(repo.all().get("key2"), Some(&Value::String("value2".to_string())));
+    }
+
+    #[test]
+    fn test_new_treats_dotted_keys_as_nested_paths() {
+        let mut items = HashMap::new();
+        items.insert(String::from("database.host"), String::from("localhost"));
+        let repo = Repository::new(items);
+
+        assert_eq!(repo.get("database.host").as_deref(), Some("localhost"));
+        assert!(repo.has("database"));
+        assert_eq!(repo.get("database.host.extra"), None);
+    }
+
+    #[test]
+    fn test_new_resolves_conflicting_prefix_and_child_keys_deterministically() {
+        let mut items = HashMap::new();
+        items.insert(String::from("database"), String::from("flat"));
+        items.insert(String::from("database.host"), String::from("localhost"));
+        let repo = Repository::new(items);
+
+        assert_eq!(repo.get("database.host").as_deref(), Some("localhost"));
+        assert_eq!(repo.get("database"), None);
+    }
+
+    #[test]
+    fn test_dotted_set_and_get_creates_nested_tables() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set("database.host", "localhost").unwrap();
+        repo.set("database.port", "5432").unwrap();
+
+        assert_eq!(repo.get("database.host").as_deref(), Some("localhost"));
+        assert_eq!(repo.get("database.port").as_deref(), Some("5432"));
+        assert!(repo.has("database"));
+        assert_eq!(repo.get("database"), None);
+    }
+
+    #[test]
+    fn test_get_missing_intermediate_segment_is_none() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set("database.host", "localhost").unwrap();
+
+        assert_eq!(repo.get("database.host.extra"), None);
+        assert_eq!(repo.get("missing.path"), None);
+    }
+
+    #[test]
+    fn test_merge_combines_overlapping_partial_paths() {
+        let mut base = Repository::new(HashMap::new());
+        base.set("a.b", "1").unwrap();
+
+        let mut incoming = Repository::new(HashMap::new());
+        incoming.set("a.c", "2").unwrap();
+
+        base.merge(&incoming).unwrap();
+
+        assert_eq!(base.get("a.b").as_deref(), Some("1"));
+        assert_eq!(base.get("a.c").as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_merge_overwrites_leaf_with_incoming_value() {
+        let mut base = Repository::new(HashMap::new());
+        base.set("a.b", "1").unwrap();
+
+        let mut incoming = Repository::new(HashMap::new());
+        incoming.set("a.b", "2").unwrap();
+
+        base.merge(&incoming).unwrap();
+
+        assert_eq!(base.get("a.b").as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_get_bool_coerces_string_and_integer() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set("debug", "true").unwrap();
+        repo.set_default("flag", Value::Integer(1)).unwrap();
+
+        assert_eq!(repo.get_bool("debug"), Some(true));
+        assert_eq!(repo.get_bool("flag"), Some(true));
+        assert_eq!(repo.get_bool("missing"), None);
+    }
+
+    #[test]
+    fn test_get_int_coerces_string_and_float() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set("port", "8080").unwrap();
+        repo.set_default("ratio", Value::Float(2.9)).unwrap();
+
+        assert_eq!(repo.get_int("port"), Some(8080));
+        assert_eq!(repo.get_int("ratio"), Some(2));
+        assert_eq!(repo.get_int("missing"), None);
+    }
+
+    #[test]
+    fn test_get_str_rejects_non_string_values() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set("name", "repository").unwrap();
+        repo.set_default("count", Value::Integer(3)).unwrap();
+
+        assert_eq!(repo.get_str("name"), Some("repository"));
+        assert_eq!(repo.get_str("count"), None);
+    }
+
+    #[test]
+    fn test_get_array_and_get_table() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set_default(
+            "ports",
+            Value::Array(vec![Value::Integer(80), Value::Integer(443)]),
+        )
+        .unwrap();
+        repo.set("database.host", "localhost").unwrap();
+
+        assert_eq!(
+            repo.get_array("ports"),
+            Some(&vec![Value::Integer(80), Value::Integer(443)])
+        );
+        assert!(repo.get_table("database").is_some());
+        assert_eq!(repo.get_array("database"), None);
+        assert_eq!(repo.get_table("ports"), None);
+    }
+
+    #[test]
+    fn test_dotted_path_indexes_into_arrays() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set_default(
+            "server.ports",
+            Value::Array(vec![Value::Integer(80), Value::Integer(443)]),
+        )
+        .unwrap();
+
+        assert_eq!(repo.get_int("server.ports.0"), Some(80));
+        assert_eq!(repo.get_int("server.ports.1"), Some(443));
+        assert_eq!(repo.get("server.ports.2"), None);
+        assert_eq!(repo.get("server.ports.not_a_number"), None);
+    }
+
+    struct StaticSource(HashMap<String, Value>);
+
+    impl Source for StaticSource {
+        fn collect(&self) -> HashMap<String, Value> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_overrides_take_precedence_over_sources_and_defaults() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set_default("env", Value::String("default".to_string())).unwrap();
+
+        let mut source_items = HashMap::new();
+        source_items.insert("env".to_string(), Value::String("source".to_string()));
+        source_items.insert("only_source".to_string(), Value::String("present".to_string()));
+        repo.add_source(Box::new(StaticSource(source_items))).unwrap();
+
+        assert_eq!(repo.get("env").as_deref(), Some("source"));
+        assert_eq!(repo.get("only_source").as_deref(), Some("present"));
+
+        repo.set("env", "override").unwrap();
+        assert_eq!(repo.get("env").as_deref(), Some("override"));
+    }
+
+    #[test]
+    fn test_later_sources_take_precedence_over_earlier_ones() {
+        let mut repo = Repository::new(HashMap::new());
+
+        let mut first = HashMap::new();
+        first.insert("env".to_string(), Value::String("first".to_string()));
+        repo.add_source(Box::new(StaticSource(first))).unwrap();
+
+        let mut second = HashMap::new();
+        second.insert("env".to_string(), Value::String("second".to_string()));
+        repo.add_source(Box::new(StaticSource(second))).unwrap();
+
+        assert_eq!(repo.get("env").as_deref(), Some("second"));
+    }
+
+    #[test]
+    fn test_freeze_preserves_resolved_values() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set("key", "value").unwrap();
+
+        let frozen = repo.freeze();
+
+        assert_eq!(frozen.get("key").as_deref(), Some("value"));
+    }
+
+    #[test]
+    fn test_frozen_repository_rejects_mutation() {
+        let repo = Repository::new(HashMap::new());
+        let mut frozen = repo.freeze();
+
+        assert_eq!(frozen.set("key", "value"), Err(FrozenError));
+        assert_eq!(frozen.set_default("key", Value::Integer(1)), Err(FrozenError));
+        assert_eq!(
+            frozen.add_source(Box::new(StaticSource(HashMap::new()))),
+            Err(FrozenError)
+        );
+        assert_eq!(frozen.refresh(), Err(FrozenError));
+
+        let other = Repository::new(HashMap::new());
+        assert_eq!(frozen.merge(&other), Err(FrozenError));
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct DatabaseSettings {
+        host: String,
+        port: i64,
+        #[serde(default)]
+        ssl: bool,
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct AppSettings {
+        database: DatabaseSettings,
+        ports: Vec<i64>,
+    }
+
+    #[test]
+    fn test_deserialize_nested_struct_and_array() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set("database.host", "localhost").unwrap();
+        repo.set("database.port", "5432").unwrap();
+        repo.set_default(
+            "ports",
+            Value::Array(vec![Value::Integer(80), Value::Integer(443)]),
+        )
+        .unwrap();
+
+        let settings: AppSettings = repo.deserialize().unwrap();
+
+        assert_eq!(
+            settings,
+            AppSettings {
+                database: DatabaseSettings {
+                    host: "localhost".to_string(),
+                    port: 5432,
+                    ssl: false,
+                },
+                ports: vec![80, 443],
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_reports_path_on_type_mismatch() {
+        let mut repo = Repository::new(HashMap::new());
+        repo.set("database.host", "localhost").unwrap();
+        repo.set("database.port", "not_a_number").unwrap();
+        repo.set_default("ports", Value::Array(Vec::new())).unwrap();
+
+        let error = repo.deserialize::<AppSettings>().unwrap_err();
+
+        match error {
+            Error::Deserialize(message) => assert!(message.contains("database.port")),
+            other => panic!("expected Error::Deserialize, got {other:?}"),
+        }
     }
 }
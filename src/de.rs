@@ -0,0 +1,196 @@
+use std::collections::hash_map;
+use std::fmt;
+use std::slice;
+
+use serde::de::{self, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+
+use crate::config::Value;
+use crate::error::Error;
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Error::Deserialize(message.to_string())
+    }
+}
+
+/// Deserializes a [`Value`] tree into any `T: DeserializeOwned`, backing
+/// [`Repository::deserialize`](crate::config::Repository::deserialize).
+///
+/// Tracks the dotted key path walked so far, so a type mismatch several
+/// levels deep reports the offending key rather than just "invalid type".
+pub(crate) struct ValueDeserializer<'de> {
+    value: &'de Value,
+    path: Vec<String>,
+}
+
+impl<'de> ValueDeserializer<'de> {
+    pub(crate) fn new(value: &'de Value) -> Self {
+        Self {
+            value,
+            path: Vec::new(),
+        }
+    }
+
+    fn child(&self, value: &'de Value, segment: String) -> Self {
+        let mut path = self.path.clone();
+        path.push(segment);
+        Self { value, path }
+    }
+
+    /// Prefixes `err` with this deserializer's dotted path, unless `err`
+    /// already carries one (an error bubbling up from a deeper field already
+    /// named the exact key that failed, so an ancestor re-wrapping it would
+    /// just repeat the prefix at every level on the way up).
+    fn with_path(&self, err: Error) -> Error {
+        match err {
+            Error::Deserialize(message) if message.starts_with("at `") => {
+                Error::Deserialize(message)
+            }
+            Error::Deserialize(message) if !self.path.is_empty() => {
+                Error::Deserialize(format!("at `{}`: {}", self.path.join("."), message))
+            }
+            other => other,
+        }
+    }
+}
+
+/// Forwards a scalar `deserialize_*` method to `deserialize_any`, except when
+/// the underlying value is a `Value::String` — then it parses the string the
+/// same way [`ConfigContract::get_bool`](crate::config::ConfigContract::get_bool)
+/// and [`ConfigContract::get_int`](crate::config::ConfigContract::get_int) do,
+/// so a struct field backed by a `Repository::set`/`EnvSource` string value
+/// still deserializes into a `bool`/number instead of erroring.
+macro_rules! deserialize_scalar_with_string_coercion {
+    ($method:ident, $target:ty, $visit:ident, $expected:literal) => {
+        fn $method<V>(self, visitor: V) -> Result<V::Value, Error>
+        where
+            V: Visitor<'de>,
+        {
+            match self.value {
+                Value::String(s) => match s.parse::<$target>() {
+                    Ok(parsed) => visitor.$visit(parsed).map_err(|err| self.with_path(err)),
+                    Err(_) => Err(self.with_path(Error::Deserialize(format!(
+                        "expected {}, found string `{}`",
+                        $expected, s
+                    )))),
+                },
+                _ => self.deserialize_any(visitor),
+            }
+        }
+    };
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let outcome = match self.value {
+            Value::Nil => visitor.visit_unit(),
+            Value::Boolean(b) => visitor.visit_bool(*b),
+            Value::Integer(i) => visitor.visit_i64(*i),
+            Value::Float(f) => visitor.visit_f64(*f),
+            Value::String(s) => visitor.visit_str(s),
+            Value::Array(items) => visitor.visit_seq(SeqValueAccess {
+                parent: &self,
+                items: items.iter(),
+                index: 0,
+            }),
+            Value::Table(map) => visitor.visit_map(MapValueAccess {
+                parent: &self,
+                iter: map.iter(),
+                value: None,
+            }),
+        };
+        outcome.map_err(|err| self.with_path(err))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Nil => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    deserialize_scalar_with_string_coercion!(deserialize_bool, bool, visit_bool, "a bool");
+    deserialize_scalar_with_string_coercion!(deserialize_i8, i64, visit_i64, "an integer");
+    deserialize_scalar_with_string_coercion!(deserialize_i16, i64, visit_i64, "an integer");
+    deserialize_scalar_with_string_coercion!(deserialize_i32, i64, visit_i64, "an integer");
+    deserialize_scalar_with_string_coercion!(deserialize_i64, i64, visit_i64, "an integer");
+    deserialize_scalar_with_string_coercion!(deserialize_i128, i128, visit_i128, "an integer");
+    deserialize_scalar_with_string_coercion!(deserialize_u8, u64, visit_u64, "an unsigned integer");
+    deserialize_scalar_with_string_coercion!(deserialize_u16, u64, visit_u64, "an unsigned integer");
+    deserialize_scalar_with_string_coercion!(deserialize_u32, u64, visit_u64, "an unsigned integer");
+    deserialize_scalar_with_string_coercion!(deserialize_u64, u64, visit_u64, "an unsigned integer");
+    deserialize_scalar_with_string_coercion!(deserialize_u128, u128, visit_u128, "an unsigned integer");
+    deserialize_scalar_with_string_coercion!(deserialize_f32, f64, visit_f64, "a float");
+    deserialize_scalar_with_string_coercion!(deserialize_f64, f64, visit_f64, "a float");
+
+    serde::forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct newtype_struct seq
+        tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct SeqValueAccess<'p, 'de> {
+    parent: &'p ValueDeserializer<'de>,
+    items: slice::Iter<'de, Value>,
+    index: usize,
+}
+
+impl<'p, 'de> SeqAccess<'de> for SeqValueAccess<'p, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        match self.items.next() {
+            Some(value) => {
+                let segment = self.index.to_string();
+                self.index += 1;
+                seed.deserialize(self.parent.child(value, segment)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+struct MapValueAccess<'p, 'de> {
+    parent: &'p ValueDeserializer<'de>,
+    iter: hash_map::Iter<'de, String, Value>,
+    value: Option<(&'de str, &'de Value)>,
+}
+
+impl<'p, 'de> MapAccess<'de> for MapValueAccess<'p, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some((key.as_str(), value));
+                seed.deserialize(key.as_str().into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value, Error>
+    where
+        S: de::DeserializeSeed<'de>,
+    {
+        let (key, value) = self
+            .value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        seed.deserialize(self.parent.child(value, key.to_string()))
+    }
+}
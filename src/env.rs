@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::env;
+
+use crate::config::{set_path, Value};
+use crate::source::Source;
+
+/// A [`Source`] that harvests process environment variables into config.
+///
+/// Keys are optionally filtered by [`with_prefix`](EnvSource::with_prefix)
+/// (which is then stripped), lowercased, and split on
+/// [`separator`](EnvSource::with_separator) to form a dotted path — so with
+/// prefix `"APP_"` and separator `"__"`, `APP_DATABASE__HOST` contributes
+/// `database.host`. Values are coerced the same way a typed accessor would:
+/// `"true"`/`"false"` become `Boolean`, numeric strings become
+/// `Integer`/`Float`, and everything else stays a `String`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use repository::env::EnvSource;
+///
+/// let source = EnvSource::new().with_prefix("APP_").with_separator("__");
+/// ```
+pub struct EnvSource {
+    prefix: Option<String>,
+    separator: String,
+}
+
+impl EnvSource {
+    /// Builds an `EnvSource` with no prefix filter and `_` as the nesting
+    /// separator.
+    pub fn new() -> Self {
+        Self {
+            prefix: None,
+            separator: "_".to_string(),
+        }
+    }
+
+    /// Restricts collection to variables starting with `prefix`, which is
+    /// stripped before the remainder is lowercased and split.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Sets the separator used to split a variable name into a dotted path.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    fn coerce(raw: &str) -> Value {
+        if let Ok(boolean) = raw.parse::<bool>() {
+            Value::Boolean(boolean)
+        } else if let Ok(integer) = raw.parse::<i64>() {
+            Value::Integer(integer)
+        } else if let Ok(float) = raw.parse::<f64>() {
+            Value::Float(float)
+        } else {
+            Value::String(raw.to_string())
+        }
+    }
+}
+
+impl Default for EnvSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Source for EnvSource {
+    fn collect(&self) -> HashMap<String, Value> {
+        let mut table = HashMap::new();
+        for (name, raw) in env::vars() {
+            let stripped = match &self.prefix {
+                Some(prefix) => match name.strip_prefix(prefix) {
+                    Some(rest) => rest,
+                    None => continue,
+                },
+                None => name.as_str(),
+            };
+            let path = stripped.to_lowercase().replace(&self.separator, ".");
+            set_path(&mut table, &path, Self::coerce(&raw));
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coerce_parses_bool_int_and_float() {
+        assert_eq!(EnvSource::coerce("true"), Value::Boolean(true));
+        assert_eq!(EnvSource::coerce("42"), Value::Integer(42));
+        assert_eq!(EnvSource::coerce("3.5"), Value::Float(3.5));
+        assert_eq!(
+            EnvSource::coerce("localhost"),
+            Value::String("localhost".to_string())
+        );
+    }
+
+    #[test]
+    fn test_collect_strips_prefix_lowercases_and_nests() {
+        env::set_var("APP_DATABASE__HOST", "localhost");
+        env::set_var("APP_DATABASE__PORT", "5432");
+        env::set_var("OTHER_VAR", "ignored");
+
+        let source = EnvSource::new().with_prefix("APP_").with_separator("__");
+        let table = source.collect();
+
+        match table.get("database") {
+            Some(Value::Table(database)) => {
+                assert_eq!(
+                    database.get("host"),
+                    Some(&Value::String("localhost".to_string()))
+                );
+                assert_eq!(database.get("port"), Some(&Value::Integer(5432)));
+            }
+            other => panic!("expected a table at \"database\", got {other:?}"),
+        }
+        assert!(!table.contains_key("other_var"));
+
+        env::remove_var("APP_DATABASE__HOST");
+        env::remove_var("APP_DATABASE__PORT");
+        env::remove_var("OTHER_VAR");
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// An error produced while loading or parsing configuration.
+#[derive(Debug)]
+pub enum Error {
+    /// Reading the underlying file or stream failed.
+    Io(String),
+    /// A file's extension didn't match a known format.
+    UnknownFormat(Option<String>),
+    /// The content didn't parse, or didn't parse into a table.
+    Parse(String),
+    /// Deserializing the config into a target type failed, or the target's
+    /// `Deserialize` impl rejected it.
+    Deserialize(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(message) => write!(f, "failed to read config file: {message}"),
+            Error::UnknownFormat(Some(ext)) => write!(f, "unrecognized config format: .{ext}"),
+            Error::UnknownFormat(None) => {
+                write!(f, "config file has no extension to infer a format from")
+            }
+            Error::Parse(message) => write!(f, "failed to parse config: {message}"),
+            Error::Deserialize(message) => write!(f, "failed to deserialize config: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::Value;
+use crate::error::Error;
+use crate::source::Source;
+
+/// Parses a config file's raw text into the crate's [`Value`] tree.
+///
+/// Implementations flatten nested tables and arrays so dotted lookups like
+/// `get("server.ports.0")` resolve through the parsed structure.
+pub trait Format {
+    /// Parses `text` into a flat key-to-value map, with nested structure
+    /// expressed through `Value::Table` and `Value::Array`.
+    fn parse(&self, text: &str) -> Result<HashMap<String, Value>, Error>;
+}
+
+/// Parses JSON text via `serde_json`.
+pub struct JsonFormat;
+
+impl Format for JsonFormat {
+    fn parse(&self, text: &str) -> Result<HashMap<String, Value>, Error> {
+        let parsed: serde_json::Value =
+            serde_json::from_str(text).map_err(|err| Error::Parse(err.to_string()))?;
+        table_from_value(json_to_value(parsed))
+    }
+}
+
+/// Parses TOML text via the `toml` crate.
+pub struct TomlFormat;
+
+impl Format for TomlFormat {
+    fn parse(&self, text: &str) -> Result<HashMap<String, Value>, Error> {
+        let parsed: toml::Value =
+            toml::from_str(text).map_err(|err| Error::Parse(err.to_string()))?;
+        table_from_value(toml_to_value(parsed))
+    }
+}
+
+/// Parses YAML text via `serde_yaml`.
+pub struct YamlFormat;
+
+impl Format for YamlFormat {
+    fn parse(&self, text: &str) -> Result<HashMap<String, Value>, Error> {
+        let parsed: serde_yaml::Value =
+            serde_yaml::from_str(text).map_err(|err| Error::Parse(err.to_string()))?;
+        table_from_value(yaml_to_value(parsed))
+    }
+}
+
+fn table_from_value(value: Value) -> Result<HashMap<String, Value>, Error> {
+    match value {
+        Value::Table(table) => Ok(table),
+        Value::Nil => Ok(HashMap::new()),
+        other => Err(Error::Parse(format!(
+            "expected a table at the document root, found {other:?}"
+        ))),
+    }
+}
+
+fn json_to_value(value: serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Nil,
+        serde_json::Value::Bool(b) => Value::Boolean(b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Integer(i),
+            None => Value::Float(n.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(s) => Value::String(s),
+        serde_json::Value::Array(items) => {
+            Value::Array(items.into_iter().map(json_to_value).collect())
+        }
+        serde_json::Value::Object(map) => Value::Table(
+            map.into_iter()
+                .map(|(key, value)| (key, json_to_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn toml_to_value(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::Integer(i),
+        toml::Value::Float(f) => Value::Float(f),
+        toml::Value::Boolean(b) => Value::Boolean(b),
+        toml::Value::Datetime(dt) => Value::String(dt.to_string()),
+        toml::Value::Array(items) => Value::Array(items.into_iter().map(toml_to_value).collect()),
+        toml::Value::Table(map) => Value::Table(
+            map.into_iter()
+                .map(|(key, value)| (key, toml_to_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn yaml_to_value(value: serde_yaml::Value) -> Value {
+    match value {
+        serde_yaml::Value::Null => Value::Nil,
+        serde_yaml::Value::Bool(b) => Value::Boolean(b),
+        serde_yaml::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Integer(i),
+            None => Value::Float(n.as_f64().unwrap_or_default()),
+        },
+        serde_yaml::Value::String(s) => Value::String(s),
+        serde_yaml::Value::Sequence(items) => {
+            Value::Array(items.into_iter().map(yaml_to_value).collect())
+        }
+        serde_yaml::Value::Mapping(map) => Value::Table(
+            map.into_iter()
+                .filter_map(|(key, value)| match key {
+                    serde_yaml::Value::String(key) => Some((key, yaml_to_value(value))),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        serde_yaml::Value::Tagged(tagged) => yaml_to_value(tagged.value),
+    }
+}
+
+/// A [`Source`] that reads a config file and parses it through a [`Format`].
+///
+/// The format is inferred from the file extension unless one is supplied
+/// explicitly via [`FileSource::with_format`]. Marking a source
+/// [`optional`](FileSource::optional) means a missing file contributes an
+/// empty map instead of failing, which is what makes environment-specific
+/// overlay files (`config.local.yaml`, say) safe to add unconditionally.
+pub struct FileSource {
+    path: PathBuf,
+    format: Box<dyn Format>,
+    optional: bool,
+}
+
+impl FileSource {
+    /// Builds a `FileSource` whose format is inferred from `path`'s
+    /// extension (`.json`, `.toml`, `.yaml`/`.yml`).
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let format = Self::format_for_extension(&path)?;
+        Ok(Self {
+            path,
+            format,
+            optional: false,
+        })
+    }
+
+    /// Builds a `FileSource` with an explicit format, bypassing extension
+    /// detection.
+    pub fn with_format(path: impl Into<PathBuf>, format: Box<dyn Format>) -> Self {
+        Self {
+            path: path.into(),
+            format,
+            optional: false,
+        }
+    }
+
+    /// Marks this source as optional: a missing file yields an empty map
+    /// instead of an error.
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Reads and parses the file, honoring `optional`.
+    ///
+    /// Use this directly (before [`Repository::add_source`](crate::config::Repository::add_source))
+    /// when a missing or malformed required file should fail startup rather
+    /// than silently contributing nothing.
+    pub fn load(&self) -> Result<HashMap<String, Value>, Error> {
+        match fs::read_to_string(&self.path) {
+            Ok(text) => self.format.parse(&text),
+            Err(err) if self.optional && err.kind() == std::io::ErrorKind::NotFound => {
+                Ok(HashMap::new())
+            }
+            Err(err) => Err(Error::Io(err.to_string())),
+        }
+    }
+
+    fn format_for_extension(path: &Path) -> Result<Box<dyn Format>, Error> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Ok(Box::new(JsonFormat)),
+            Some("toml") => Ok(Box::new(TomlFormat)),
+            Some("yaml" | "yml") => Ok(Box::new(YamlFormat)),
+            other => Err(Error::UnknownFormat(other.map(str::to_string))),
+        }
+    }
+}
+
+impl Source for FileSource {
+    fn collect(&self) -> HashMap<String, Value> {
+        self.load().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_format_flattens_nested_tables_and_arrays() {
+        let table = JsonFormat
+            .parse(r#"{"server": {"host": "localhost", "ports": [80, 443]}}"#)
+            .unwrap();
+
+        match table.get("server") {
+            Some(Value::Table(server)) => {
+                assert_eq!(server.get("host"), Some(&Value::String("localhost".to_string())));
+                assert_eq!(
+                    server.get("ports"),
+                    Some(&Value::Array(vec![Value::Integer(80), Value::Integer(443)]))
+                );
+            }
+            other => panic!("expected a table at \"server\", got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_toml_format_parses_scalars() {
+        let table = TomlFormat.parse("debug = true\nport = 8080\n").unwrap();
+
+        assert_eq!(table.get("debug"), Some(&Value::Boolean(true)));
+        assert_eq!(table.get("port"), Some(&Value::Integer(8080)));
+    }
+
+    #[test]
+    fn test_yaml_format_parses_nested_mapping() {
+        let table = YamlFormat.parse("database:\n  host: localhost\n").unwrap();
+
+        match table.get("database") {
+            Some(Value::Table(database)) => {
+                assert_eq!(
+                    database.get("host"),
+                    Some(&Value::String("localhost".to_string()))
+                );
+            }
+            other => panic!("expected a table at \"database\", got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_new_infers_format_from_extension() {
+        assert!(FileSource::new("config.json").is_ok());
+        assert!(FileSource::new("config.toml").is_ok());
+        assert!(FileSource::new("config.yaml").is_ok());
+        assert!(FileSource::new("config.yml").is_ok());
+        assert!(matches!(
+            FileSource::new("config.ini"),
+            Err(Error::UnknownFormat(Some(ext))) if ext == "ini"
+        ));
+    }
+
+    #[test]
+    fn test_optional_missing_file_collects_as_empty() {
+        let source = FileSource::new("definitely-missing.json").unwrap().optional();
+        assert_eq!(source.collect(), HashMap::new());
+    }
+
+    #[test]
+    fn test_required_missing_file_fails_to_load() {
+        let source = FileSource::new("definitely-missing.json").unwrap();
+        assert!(source.load().is_err());
+    }
+}
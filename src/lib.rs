@@ -0,0 +1,6 @@
+pub mod config;
+mod de;
+pub mod env;
+pub mod error;
+pub mod file;
+pub mod source;
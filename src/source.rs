@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+use crate::config::Value;
+
+/// A layer of configuration that a [`Repository`](crate::config::Repository)
+/// can compose into its precedence chain.
+///
+/// Sources are read through `collect` each time the repository refreshes, so
+/// implementations are free to re-poll whatever backs them (a file, the
+/// process environment, a remote store) rather than caching a stale view.
+pub trait Source {
+    /// Collects this source's current view of the configuration as a flat
+    /// key-to-value map, with nested structure expressed through
+    /// `Value::Table`.
+    fn collect(&self) -> HashMap<String, Value>;
+}